@@ -1,5 +1,7 @@
 use std::io;
-use std::path::Path;
+use std::io::BufRead;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::ffi::OsStr;
 use std::collections::BTreeMap;
 
@@ -9,36 +11,112 @@ use proguard;
 use console::style;
 use serde_json;
 use serde::ser::{Serialize, Serializer, SerializeStruct};
+use walkdir::WalkDir;
 
 use prelude::*;
 use config::Config;
 use utils::MachoInfo;
 use commands::difutil_find::DifType;
 
-enum DifRepr {
+/// The parsed contents of a Breakpad text symbol file (`.sym`).
+///
+/// Only the `MODULE` header and the presence of function/line records are
+/// tracked; the rest of the file (FILE/PUBLIC records, line tables) is not
+/// needed for the usability check this command performs.
+struct BreakpadInfo {
+    uuid: Uuid,
+    arch: String,
+    has_records: bool,
+}
+
+impl BreakpadInfo {
+    pub fn open_path<P: AsRef<Path>>(path: P) -> Result<BreakpadInfo> {
+        let f = fs::File::open(path)?;
+        let mut lines = io::BufReader::new(f).lines();
+
+        let header = match lines.next() {
+            Some(Ok(ref line)) if line.starts_with("MODULE ") => line.clone(),
+            _ => fail!("not a breakpad symbol file"),
+        };
+
+        let mut parts = header.trim_right().splitn(5, ' ');
+        parts.next(); // "MODULE"
+        parts.next(); // os
+        let arch = match parts.next() {
+            Some(arch) => arch,
+            None => fail!("malformed MODULE line"),
+        };
+        let debug_id = match parts.next() {
+            Some(debug_id) => debug_id,
+            None => fail!("malformed MODULE line"),
+        };
+
+        // the first 32 hex chars are the UUID; any trailing age nibble is dropped.
+        if debug_id.len() < 32 {
+            fail!("malformed debug id in MODULE line");
+        }
+        let uuid = Uuid::parse_str(&debug_id[..32])?;
+
+        let mut has_records = false;
+        for line in lines {
+            let line = line?;
+            let is_address_record = line.split_whitespace().next()
+                .map_or(false, |tok| !tok.is_empty() && tok.chars().all(|c| c.is_digit(16)));
+            if line.starts_with("FUNC ") || is_address_record {
+                has_records = true;
+                break;
+            }
+        }
+
+        Ok(BreakpadInfo {
+            uuid: uuid,
+            arch: arch.to_string(),
+            has_records: has_records,
+        })
+    }
+
+    pub fn uuid(&self) -> Uuid {
+        self.uuid
+    }
+
+    pub fn arch(&self) -> &str {
+        &self.arch
+    }
+
+    pub fn has_func_records(&self) -> bool {
+        self.has_records
+    }
+}
+
+pub(crate) enum DifRepr {
     Dsym(MachoInfo),
     Proguard(proguard::MappingView<'static>),
+    Breakpad(BreakpadInfo),
 }
 
 impl DifRepr {
-    pub fn ty(&self) -> DifType {
+    pub(crate) fn ty(&self) -> DifType {
         match self {
             &DifRepr::Dsym(..) => DifType::Dsym,
             &DifRepr::Proguard(..) => DifType::Proguard,
+            &DifRepr::Breakpad(..) => DifType::Breakpad,
         }
     }
 
-    pub fn variants(&self) -> BTreeMap<Uuid, Option<&'static str>> {
+    pub(crate) fn variants(&self) -> BTreeMap<Uuid, Option<String>> {
         match self {
             &DifRepr::Dsym(ref mi) => {
                 mi.get_architectures()
                     .into_iter()
-                    .map(|(key, value)| (key, Some(value)))
+                    .map(|(key, value)| (key, Some(value.to_string())))
                     .collect()
             }
             &DifRepr::Proguard(ref pg) => {
                 vec![(pg.uuid(), None)].into_iter().collect()
             }
+            &DifRepr::Breakpad(ref bi) => {
+                vec![(bi.uuid(), Some(bi.arch().to_string()))].into_iter().collect()
+            }
         }
     }
 
@@ -46,6 +124,7 @@ impl DifRepr {
         match self {
             &DifRepr::Dsym(ref mi) => mi.has_debug_info(),
             &DifRepr::Proguard(ref pg) => pg.has_line_info(),
+            &DifRepr::Breakpad(ref bi) => bi.has_func_records(),
         }
     }
 
@@ -56,6 +135,7 @@ impl DifRepr {
             Some(match self {
                 &DifRepr::Dsym(..) => "missing DWARF debug info",
                 &DifRepr::Proguard(..) => "missing line information",
+                &DifRepr::Breakpad(..) => "missing function records",
             })
         }
     }
@@ -75,6 +155,209 @@ impl Serialize for DifRepr {
     }
 }
 
+/// Attempts to build a `DifRepr` for the given path, optionally restricted
+/// to an explicitly requested `DifType`.
+///
+/// This is the auto-detection logic shared between single-file checks and
+/// the recursive directory scan: a `.sym` extension is tried as Breakpad
+/// first, then it tries dSYM, then falls back to Proguard, then Breakpad
+/// again regardless of extension, and only fails if none of the formats
+/// recognize the file.
+pub(crate) fn detect_repr(path: &Path, ty: Option<DifType>) -> Result<DifRepr> {
+    Ok(match ty {
+        Some(DifType::Dsym) => DifRepr::Dsym(MachoInfo::open_path(&path)?),
+        Some(DifType::Proguard) => DifRepr::Proguard(proguard::MappingView::from_path(&path)?),
+        Some(DifType::Breakpad) => DifRepr::Breakpad(BreakpadInfo::open_path(&path)?),
+        None => {
+            if path.extension() == Some(OsStr::new("sym")) {
+                if let Ok(bi) = BreakpadInfo::open_path(&path) {
+                    return Ok(DifRepr::Breakpad(bi));
+                }
+            }
+
+            if let Ok(mi) = MachoInfo::open_path(&path) {
+                DifRepr::Dsym(mi)
+            } else {
+                match proguard::MappingView::from_path(&path) {
+                    Ok(pg) => {
+                        if path.extension() == Some(OsStr::new("txt")) ||
+                           pg.has_line_info() {
+                            DifRepr::Proguard(pg)
+                        } else if let Ok(bi) = BreakpadInfo::open_path(&path) {
+                            DifRepr::Breakpad(bi)
+                        } else {
+                            fail!("invalid debug info file");
+                        }
+                    }
+                    Err(err) => {
+                        if let Ok(bi) = BreakpadInfo::open_path(&path) {
+                            return Ok(DifRepr::Breakpad(bi));
+                        }
+                        return Err(err.into());
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// A single entry of a recursive scan report, pairing the discovered file's
+/// path with its parsed `DifRepr`.
+struct DifFileReport {
+    path: PathBuf,
+    repr: DifRepr,
+}
+
+impl Serialize for DifFileReport {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        // 5 is the number of fields in the struct.
+        let mut state = serializer.serialize_struct("DifFileReport", 5)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("type", &self.repr.ty())?;
+        state.serialize_field("variants", &self.repr.variants())?;
+        state.serialize_field("is_usable", &self.repr.is_usable())?;
+        state.serialize_field("problem", &self.repr.get_problem())?;
+        state.end()
+    }
+}
+
+/// Walks `root` recursively and attempts to detect a `DifRepr` for every
+/// regular file found beneath it, skipping anything that doesn't parse as a
+/// known debug info format.
+fn scan_recursive(root: &Path, ty: Option<DifType>) -> Vec<DifFileReport> {
+    let mut found = vec![];
+    for entry in WalkDir::new(root).into_iter().filter_map(|e| e.ok()) {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        if let Ok(repr) = detect_repr(entry.path(), ty) {
+            found.push(DifFileReport {
+                path: entry.path().to_path_buf(),
+                repr: repr,
+            });
+        }
+    }
+    found
+}
+
+/// One parsed line of a `--manifest` file: the debug info file to check and,
+/// optionally, the UUID it's expected to contain.
+struct ManifestEntry {
+    line: usize,
+    path: PathBuf,
+    expected_uuid: Option<Uuid>,
+}
+
+/// Parses a manifest in the `<path> [<expected-uuid>]` format, trimming `#`
+/// comments and skipping blank lines. Line numbers are preserved so that
+/// later verification failures can point back at the offending line.
+fn parse_manifest(path: &Path) -> Result<Vec<ManifestEntry>> {
+    let f = fs::File::open(path)?;
+    let mut entries = vec![];
+
+    for (idx, line) in io::BufReader::new(f).lines().enumerate() {
+        let line_no = idx + 1;
+        let line = line?;
+        let without_comment = match line.find('#') {
+            Some(pos) => &line[..pos],
+            None => &line[..],
+        };
+        let trimmed = without_comment.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let mut parts = trimmed.split_whitespace();
+        let entry_path = PathBuf::from(parts.next().unwrap());
+        let expected_uuid = match parts.next() {
+            Some(raw) => {
+                match Uuid::parse_str(raw) {
+                    Ok(uuid) => Some(uuid),
+                    Err(..) => fail!("line {}: invalid UUID {:?}", line_no, raw),
+                }
+            }
+            None => None,
+        };
+
+        entries.push(ManifestEntry {
+            line: line_no,
+            path: entry_path,
+            expected_uuid: expected_uuid,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// The outcome of verifying a single `ManifestEntry` against the debug info
+/// file it refers to.
+struct ManifestCheckResult {
+    line: usize,
+    path: PathBuf,
+    ok: bool,
+    problem: Option<String>,
+}
+
+impl Serialize for ManifestCheckResult {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        // 3 is the number of fields in the struct.
+        let mut state = serializer.serialize_struct("ManifestCheckResult", 3)?;
+        state.serialize_field("line", &self.line)?;
+        state.serialize_field("ok", &self.ok)?;
+        state.serialize_field("problem", &self.problem)?;
+        state.end()
+    }
+}
+
+/// Verifies every entry of a parsed manifest, checking that the referenced
+/// file is usable and, if an expected UUID was given, that it's among the
+/// file's variants.
+fn check_manifest(entries: &[ManifestEntry]) -> Vec<ManifestCheckResult> {
+    entries.iter().map(|entry| {
+        let repr = match detect_repr(&entry.path, None) {
+            Ok(repr) => repr,
+            Err(err) => {
+                return ManifestCheckResult {
+                    line: entry.line,
+                    path: entry.path.clone(),
+                    ok: false,
+                    problem: Some(err.to_string()),
+                };
+            }
+        };
+
+        if let Some(expected) = entry.expected_uuid {
+            if !repr.variants().contains_key(&expected) {
+                return ManifestCheckResult {
+                    line: entry.line,
+                    path: entry.path.clone(),
+                    ok: false,
+                    problem: Some(format!("expected UUID {} not found", expected)),
+                };
+            }
+        }
+
+        match repr.get_problem() {
+            Some(prob) => ManifestCheckResult {
+                line: entry.line,
+                path: entry.path.clone(),
+                ok: false,
+                problem: Some(prob.to_string()),
+            },
+            None => ManifestCheckResult {
+                line: entry.line,
+                path: entry.path.clone(),
+                ok: true,
+                problem: None,
+            },
+        }
+    }).collect()
+}
+
 pub fn make_app<'a, 'b: 'a>(app: App<'a, 'b>) -> App<'a, 'b> {
     app
         .about("given the path to a debug info file it checks it")
@@ -82,18 +365,66 @@ pub fn make_app<'a, 'b: 'a>(app: App<'a, 'b>) -> App<'a, 'b> {
              .long("type")
              .short("t")
              .value_name("TYPE")
-             .possible_values(&["dsym", "proguard"])
+             .possible_values(&["dsym", "proguard", "breakpad"])
              .help("Explicitly sets the type of the debug info file."))
         .arg(Arg::with_name("json")
              .long("json")
              .help("Returns the results as JSON"))
+        .arg(Arg::with_name("recursive")
+             .long("recursive")
+             .short("r")
+             .help("If the path is a directory, recursively scans it for debug info \
+                    files instead of treating it as a single file."))
+        .arg(Arg::with_name("manifest")
+             .long("manifest")
+             .value_name("FILE")
+             .conflicts_with("path")
+             .help("Verifies a manifest of debug info files (one `<path> [<uuid>]` \
+                    entry per line, `#` starts a comment) instead of checking a \
+                    single path."))
         .arg(Arg::with_name("path")
              .index(1)
-             .required(true)
+             .required_unless("manifest")
              .help("The path to the debug info file."))
 }
 
 pub fn execute<'a>(matches: &ArgMatches<'a>, _config: &Config) -> Result<()> {
+    if let Some(manifest_path) = matches.value_of("manifest") {
+        let entries = parse_manifest(Path::new(manifest_path))?;
+        let results = check_manifest(&entries);
+        let any_failed = results.iter().any(|r| !r.ok);
+
+        if matches.is_present("json") {
+            // grouped by path (not collapsed to one) so a manifest that
+            // lists the same path more than once doesn't lose results.
+            let mut by_path: BTreeMap<String, Vec<&ManifestCheckResult>> = BTreeMap::new();
+            for result in &results {
+                by_path.entry(result.path.display().to_string()).or_insert_with(Vec::new).push(result);
+            }
+            serde_json::to_writer_pretty(&mut io::stdout(), &by_path)?;
+            println!("");
+        } else {
+            println!("{}", style("Debug Info Manifest Check").dim().bold());
+            for result in &results {
+                if result.ok {
+                    println!("  {} {}", style("ok").green(), result.path.display());
+                } else {
+                    println!("  {} {} (line {}): {}",
+                             style("failed").red(),
+                             result.path.display(),
+                             result.line,
+                             result.problem.as_ref().map(|s| s.as_str()).unwrap_or("unknown error"));
+                }
+            }
+        }
+
+        return if any_failed {
+            Err(ErrorKind::QuietExit(1).into())
+        } else {
+            Ok(())
+        };
+    }
+
     let path = Path::new(matches.value_of("path").unwrap());
 
     // which types should we consider?
@@ -101,31 +432,39 @@ pub fn execute<'a>(matches: &ArgMatches<'a>, _config: &Config) -> Result<()> {
         match t {
             "dsym" => DifType::Dsym,
             "proguard" => DifType::Proguard,
+            "breakpad" => DifType::Breakpad,
             _ => unreachable!()
         }
     });
 
-    let repr = match ty {
-        Some(DifType::Dsym) => DifRepr::Dsym(MachoInfo::open_path(&path)?),
-        Some(DifType::Proguard) => DifRepr::Proguard(proguard::MappingView::from_path(&path)?),
-        None => {
-            if let Ok(mi) = MachoInfo::open_path(&path) {
-                DifRepr::Dsym(mi)
-            } else {
-                match proguard::MappingView::from_path(&path) {
-                    Ok(pg) => {
-                        if path.extension() == Some(OsStr::new("txt")) ||
-                           pg.has_line_info() {
-                            DifRepr::Proguard(pg)
-                        } else {
-                            fail!("invalid debug info file");
-                        }
-                    }
-                    Err(err) => { return Err(err.into()) }
+    if matches.is_present("recursive") && path.is_dir() {
+        let reports = scan_recursive(path, ty);
+        let any_unusable = reports.iter().any(|r| !r.repr.is_usable());
+
+        if matches.is_present("json") {
+            serde_json::to_writer_pretty(&mut io::stdout(), &reports)?;
+            println!("");
+        } else {
+            println!("{}", style("Debug Info File Check").dim().bold());
+            for report in &reports {
+                println!("  {}", style(report.path.display()).bold());
+                println!("    Type: {}", style(report.repr.ty()).cyan());
+                if let Some(prob) = report.repr.get_problem() {
+                    println!("    Usable: {} ({})", style("no").red(), prob);
+                } else {
+                    println!("    Usable: {}", style("yes").green());
                 }
             }
         }
-    };
+
+        return if any_unusable {
+            Err(ErrorKind::QuietExit(1).into())
+        } else {
+            Ok(())
+        };
+    }
+
+    let repr = detect_repr(path, ty)?;
 
     if matches.is_present("json") {
         serde_json::to_writer_pretty(&mut io::stdout(), &repr)?;
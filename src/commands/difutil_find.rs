@@ -0,0 +1,22 @@
+use std::fmt;
+
+/// The kind of debug info file a `DifRepr` was parsed from.
+///
+/// Shared across the `difutil` family of commands so that detection,
+/// reporting and filtering all agree on the same set of recognized formats.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize)]
+pub enum DifType {
+    Dsym,
+    Proguard,
+    Breakpad,
+}
+
+impl fmt::Display for DifType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", match *self {
+            DifType::Dsym => "dsym",
+            DifType::Proguard => "proguard",
+            DifType::Breakpad => "breakpad",
+        })
+    }
+}
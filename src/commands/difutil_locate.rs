@@ -0,0 +1,133 @@
+use std::path::{Path, PathBuf};
+use std::io;
+use std::collections::BTreeMap;
+
+use clap::{App, Arg, ArgMatches};
+use uuid::Uuid;
+use console::style;
+use serde_json;
+use serde::ser::{Serialize, Serializer, SerializeStruct};
+use walkdir::WalkDir;
+
+use prelude::*;
+use config::Config;
+use utils::MachoInfo;
+use commands::difutil_check::detect_repr;
+
+/// The outcome of searching for a debug info file that matches one of a
+/// binary's build UUIDs.
+enum LocateResult {
+    Found { path: PathBuf, uuid: Uuid },
+    NotFound { wanted: Vec<Uuid> },
+}
+
+impl Serialize for LocateResult {
+    fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        match self {
+            &LocateResult::Found { ref path, ref uuid } => {
+                let mut state = serializer.serialize_struct("LocateResult", 3)?;
+                state.serialize_field("found", &true)?;
+                state.serialize_field("path", path)?;
+                state.serialize_field("uuid", uuid)?;
+                state.end()
+            }
+            &LocateResult::NotFound { ref wanted } => {
+                let mut state = serializer.serialize_struct("LocateResult", 2)?;
+                state.serialize_field("found", &false)?;
+                state.serialize_field("wanted", wanted)?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// Searches `search_dirs` for a debug info file whose UUID matches one of
+/// `wanted`, trying every regular file via the same auto-detection logic
+/// `difutil check` uses. Returns the first match found.
+fn locate(wanted: &BTreeMap<Uuid, &'static str>, search_dirs: &[&Path]) -> Option<(PathBuf, Uuid)> {
+    for dir in search_dirs {
+        for entry in WalkDir::new(dir).into_iter().filter_map(|e| e.ok()) {
+            if !entry.file_type().is_file() {
+                continue;
+            }
+
+            let repr = match detect_repr(entry.path(), None) {
+                Ok(repr) => repr,
+                Err(..) => continue,
+            };
+
+            for (uuid, _) in repr.variants() {
+                if wanted.contains_key(&uuid) {
+                    return Some((entry.path().to_path_buf(), uuid));
+                }
+            }
+        }
+    }
+    None
+}
+
+pub fn make_app<'a, 'b: 'a>(app: App<'a, 'b>) -> App<'a, 'b> {
+    app
+        .about("locates the debug info file matching a binary's build UUID")
+        .arg(Arg::with_name("json")
+             .long("json")
+             .help("Returns the results as JSON"))
+        .arg(Arg::with_name("executable")
+             .index(1)
+             .required(true)
+             .help("The path to the executable to resolve debug info for. \
+                    Only Mach-O binaries are supported; ELF is not yet implemented."))
+        .arg(Arg::with_name("paths")
+             .index(2)
+             .multiple(true)
+             .required(true)
+             .help("One or more directories to search for matching debug info files."))
+}
+
+pub fn execute<'a>(matches: &ArgMatches<'a>, _config: &Config) -> Result<()> {
+    let exe_path = Path::new(matches.value_of("executable").unwrap());
+    let search_dirs: Vec<&Path> = matches.values_of("paths").unwrap()
+        .map(Path::new)
+        .collect();
+
+    // multiple architecture slices of a fat binary each carry their own
+    // UUID; any one of them is an acceptable match.
+    let mi = match MachoInfo::open_path(exe_path) {
+        Ok(mi) => mi,
+        Err(..) => fail!("only Mach-O executables are supported (ELF is not yet implemented): {}",
+                          exe_path.display()),
+    };
+    let wanted = mi.get_architectures();
+
+    let result = match locate(&wanted, &search_dirs) {
+        Some((path, uuid)) => LocateResult::Found { path: path, uuid: uuid },
+        None => LocateResult::NotFound { wanted: wanted.keys().cloned().collect() },
+    };
+
+    if matches.is_present("json") {
+        serde_json::to_writer_pretty(&mut io::stdout(), &result)?;
+        println!("");
+    } else {
+        println!("{}", style("Debug Info Locate").dim().bold());
+        match result {
+            LocateResult::Found { ref path, ref uuid } => {
+                println!("  Found: {}", style(path.display()).green());
+                println!("  Matched UUID: {}", style(uuid).dim());
+            }
+            LocateResult::NotFound { ref wanted } => {
+                println!("  {}", style("no match found").red());
+                println!("  Looking for:");
+                for uuid in wanted {
+                    println!("    > {}", style(uuid).dim());
+                }
+            }
+        }
+    }
+
+    match result {
+        LocateResult::Found { .. } => Ok(()),
+        LocateResult::NotFound { .. } => Err(ErrorKind::QuietExit(1).into()),
+    }
+}